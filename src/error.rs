@@ -0,0 +1,42 @@
+use thiserror::Error;
+
+/// Domain-level errors for resource operations, so callers can react to
+/// "not found" or "duplicate" distinctly from a generic database failure.
+#[derive(Debug, Error)]
+pub enum ResourceError {
+    #[error("resource not found")]
+    NotFound,
+
+    #[error("duplicate resource")]
+    Duplicate,
+
+    #[error("database error: {0}")]
+    Database(#[source] sqlx::Error),
+
+    #[error("pool error: {0}")]
+    Pool(#[source] sqlx::Error),
+}
+
+/// SQLSTATE/result codes that mean "unique or primary-key violation" across
+/// the backends this crate talks to: Postgres's `unique_violation`, and
+/// SQLite's `SQLITE_CONSTRAINT_PRIMARYKEY` / `SQLITE_CONSTRAINT_UNIQUE`
+/// extended result codes (the latter used by `AnyResourceRepository`'s
+/// SQLite test harness).
+const DUPLICATE_CODES: &[&str] = &["23505", "1555", "2067"];
+
+impl From<sqlx::Error> for ResourceError {
+    fn from(err: sqlx::Error) -> Self {
+        match &err {
+            sqlx::Error::RowNotFound => ResourceError::NotFound,
+            sqlx::Error::Database(db_err)
+                if db_err
+                    .code()
+                    .is_some_and(|code| DUPLICATE_CODES.contains(&code.as_ref())) =>
+            {
+                ResourceError::Duplicate
+            }
+            sqlx::Error::PoolTimedOut | sqlx::Error::PoolClosed => ResourceError::Pool(err),
+            _ => ResourceError::Database(err),
+        }
+    }
+}
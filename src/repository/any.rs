@@ -0,0 +1,256 @@
+use super::ResourceRepository;
+use crate::error::ResourceError;
+use crate::resource::Resource;
+use async_trait::async_trait;
+use sqlx::any::{Any, AnyKind, AnyPoolOptions};
+use sqlx::{Pool, Row};
+use std::sync::Arc;
+
+/// A [`ResourceRepository`] built on `sqlx::Pool<Any>`, used as a SQLite
+/// in-memory test harness so repository tests run fast and without a real
+/// Postgres instance. This is test-only: production always goes through
+/// [`super::PostgresResourceRepository`].
+pub struct AnyResourceRepository {
+    pool: Arc<Pool<Any>>,
+}
+
+impl AnyResourceRepository {
+    pub fn new(pool: Pool<Any>) -> Self {
+        Self {
+            pool: Arc::new(pool),
+        }
+    }
+}
+
+/// Connects to `db_url`, dispatching on its scheme (`postgres://`,
+/// `sqlite::memory:`, ...) to build an [`AnyResourceRepository`]. Only used
+/// by this module's SQLite tests; not part of the production path.
+pub async fn connect(db_url: &str) -> anyhow::Result<AnyResourceRepository> {
+    sqlx::any::install_default_drivers();
+    let pool = AnyPoolOptions::new().connect(db_url).await?;
+    Ok(AnyResourceRepository::new(pool))
+}
+
+/// Postgres uses numbered `$n` placeholders, every other backend we support
+/// uses positional `?` placeholders.
+fn placeholder(kind: AnyKind, n: usize) -> String {
+    match kind {
+        AnyKind::Postgres => format!("${n}"),
+        _ => "?".to_string(),
+    }
+}
+
+/// Postgres has case-insensitive `ILIKE`; other backends only have `LIKE`.
+fn like_operator(kind: AnyKind) -> &'static str {
+    match kind {
+        AnyKind::Postgres => "ILIKE",
+        _ => "LIKE",
+    }
+}
+
+#[async_trait]
+impl ResourceRepository for AnyResourceRepository {
+    async fn create_resource(&self, reference: String) -> Result<i32, ResourceError> {
+        let kind = self.pool.any_kind();
+        let sql = format!(
+            "INSERT INTO resources (reference) VALUES ({}) RETURNING id",
+            placeholder(kind, 1)
+        );
+
+        let row = sqlx::query(&sql)
+            .bind(reference)
+            .fetch_one(&*self.pool)
+            .await?;
+
+        row.try_get("id").map_err(ResourceError::from)
+    }
+
+    async fn read_resource(&self, id: i32) -> Result<Resource, ResourceError> {
+        let kind = self.pool.any_kind();
+        let sql = format!(
+            "SELECT id, reference FROM resources WHERE id = {}",
+            placeholder(kind, 1)
+        );
+
+        let resource = sqlx::query_as::<_, Resource>(&sql)
+            .bind(id)
+            .fetch_one(&*self.pool)
+            .await?;
+
+        Ok(resource)
+    }
+
+    async fn update_resource(&self, id: i32, reference: String) -> Result<bool, ResourceError> {
+        let kind = self.pool.any_kind();
+        let sql = format!(
+            "UPDATE resources SET reference = {} WHERE id = {}",
+            placeholder(kind, 1),
+            placeholder(kind, 2)
+        );
+
+        let rows_affected = sqlx::query(&sql)
+            .bind(reference)
+            .bind(id)
+            .execute(&*self.pool)
+            .await?
+            .rows_affected();
+
+        Ok(rows_affected > 0)
+    }
+
+    async fn delete_resource(&self, id: i32) -> Result<bool, ResourceError> {
+        let kind = self.pool.any_kind();
+        let sql = format!("DELETE FROM resources WHERE id = {}", placeholder(kind, 1));
+
+        let rows_affected = sqlx::query(&sql)
+            .bind(id)
+            .execute(&*self.pool)
+            .await?
+            .rows_affected();
+
+        Ok(rows_affected > 0)
+    }
+
+    async fn list_resources(
+        &self,
+        filter: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Resource>, ResourceError> {
+        let kind = self.pool.any_kind();
+
+        let resources = match filter {
+            Some(pattern) => {
+                let sql = format!(
+                    "SELECT id, reference FROM resources WHERE reference {} {} \
+                     ORDER BY id LIMIT {} OFFSET {}",
+                    like_operator(kind),
+                    placeholder(kind, 1),
+                    placeholder(kind, 2),
+                    placeholder(kind, 3)
+                );
+                sqlx::query_as::<_, Resource>(&sql)
+                    .bind(format!("%{pattern}%"))
+                    .bind(limit)
+                    .bind(offset)
+                    .fetch_all(&*self.pool)
+                    .await?
+            }
+            None => {
+                let sql = format!(
+                    "SELECT id, reference FROM resources ORDER BY id LIMIT {} OFFSET {}",
+                    placeholder(kind, 1),
+                    placeholder(kind, 2)
+                );
+                sqlx::query_as::<_, Resource>(&sql)
+                    .bind(limit)
+                    .bind(offset)
+                    .fetch_all(&*self.pool)
+                    .await?
+            }
+        };
+
+        Ok(resources)
+    }
+
+    async fn count_resources(&self, filter: Option<String>) -> Result<i64, ResourceError> {
+        let kind = self.pool.any_kind();
+
+        let row = match filter {
+            Some(pattern) => {
+                let sql = format!(
+                    "SELECT COUNT(*) AS count FROM resources WHERE reference {} {}",
+                    like_operator(kind),
+                    placeholder(kind, 1)
+                );
+                sqlx::query(&sql)
+                    .bind(format!("%{pattern}%"))
+                    .fetch_one(&*self.pool)
+                    .await?
+            }
+            None => {
+                sqlx::query("SELECT COUNT(*) AS count FROM resources")
+                    .fetch_one(&*self.pool)
+                    .await?
+            }
+        };
+
+        row.try_get("count").map_err(ResourceError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Connects to an in-memory SQLite database and lays down a
+    /// SQLite-flavoured `resources` table, since the Postgres migration's
+    /// `SERIAL PRIMARY KEY` isn't valid SQLite syntax.
+    async fn sqlite_repo() -> AnyResourceRepository {
+        let repo = connect("sqlite::memory:").await.unwrap();
+
+        sqlx::query("CREATE TABLE resources (id INTEGER PRIMARY KEY AUTOINCREMENT, reference TEXT)")
+            .execute(&*repo.pool)
+            .await
+            .unwrap();
+
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_crud_roundtrip_against_sqlite() {
+        let repo = sqlite_repo().await;
+
+        let id = repo.create_resource("TEST".to_string()).await.unwrap();
+
+        let resource = repo.read_resource(id).await.unwrap();
+        assert_eq!(resource.reference.as_deref(), Some("TEST"));
+
+        assert!(repo
+            .update_resource(id, "UPDATED".to_string())
+            .await
+            .unwrap());
+        let updated = repo.read_resource(id).await.unwrap();
+        assert_eq!(updated.reference.as_deref(), Some("UPDATED"));
+
+        let listed = repo.list_resources(None, 10, 0).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(repo.count_resources(None).await.unwrap(), 1);
+
+        assert!(repo.delete_resource(id).await.unwrap());
+        assert!(repo.read_resource(id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_primary_key_maps_to_duplicate_error() {
+        let repo = sqlite_repo().await;
+
+        sqlx::query("INSERT INTO resources (id, reference) VALUES (1, 'FIRST')")
+            .execute(&*repo.pool)
+            .await
+            .unwrap();
+
+        let result = sqlx::query("INSERT INTO resources (id, reference) VALUES (1, 'SECOND')")
+            .execute(&*repo.pool)
+            .await;
+
+        let err: ResourceError = result.unwrap_err().into();
+        assert!(matches!(err, ResourceError::Duplicate));
+    }
+
+    #[tokio::test]
+    async fn test_list_resources_filters_by_reference() {
+        let repo = sqlite_repo().await;
+
+        repo.create_resource("apple".to_string()).await.unwrap();
+        repo.create_resource("banana".to_string()).await.unwrap();
+
+        let matches = repo
+            .list_resources(Some("ban".to_string()), 10, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].reference.as_deref(), Some("banana"));
+    }
+}
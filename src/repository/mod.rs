@@ -0,0 +1,31 @@
+// `any` is a SQLite in-memory test harness only (see the docs on
+// `AnyResourceRepository`): production always goes through
+// `PostgresResourceRepository`, which keeps the compile-checked
+// `sqlx::query!`/`query_as!` macros for its single-row CRUD queries; its
+// list and count queries are built at runtime.
+#[cfg(test)]
+mod any;
+mod postgres;
+
+#[cfg(test)]
+pub use any::{connect, AnyResourceRepository};
+pub use postgres::{PostgresResourceRepository, ResourceTx};
+
+use crate::error::ResourceError;
+use crate::resource::Resource;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ResourceRepository {
+    async fn create_resource(&self, reference: String) -> Result<i32, ResourceError>;
+    async fn read_resource(&self, id: i32) -> Result<Resource, ResourceError>;
+    async fn update_resource(&self, id: i32, reference: String) -> Result<bool, ResourceError>;
+    async fn delete_resource(&self, id: i32) -> Result<bool, ResourceError>;
+    async fn list_resources(
+        &self,
+        filter: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Resource>, ResourceError>;
+    async fn count_resources(&self, filter: Option<String>) -> Result<i64, ResourceError>;
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Resource {
+    pub id: Option<i32>,
+    pub reference: Option<String>,
+}
+
+impl Resource {
+    pub fn new(reference: String) -> Resource {
+        Resource {
+            id: None,
+            reference: Some(reference),
+        }
+    }
+}
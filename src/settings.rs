@@ -0,0 +1,62 @@
+use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+use std::time::Duration;
+
+/// Connection and pool-sizing settings, loaded from the environment so pool
+/// size and timeouts are tunable per deployment instead of hardcoded.
+#[derive(Debug, Clone)]
+pub struct DatabaseSettings {
+    pub host: String,
+    pub port: u16,
+    pub user: String,
+    pub password: String,
+    pub database_name: String,
+    pub max_connections: u32,
+    pub acquire_timeout: Duration,
+}
+
+impl DatabaseSettings {
+    /// Reads settings from the environment, falling back to local-dev
+    /// defaults for anything unset. Call `dotenvy::dotenv()` first to load
+    /// a `.env` file into the environment.
+    pub fn from_env() -> Self {
+        Self {
+            host: std::env::var("DATABASE_HOST").unwrap_or_else(|_| "localhost".to_string()),
+            port: env_parse("DATABASE_PORT").unwrap_or(5432),
+            user: std::env::var("DATABASE_USER").unwrap_or_else(|_| "postgres".to_string()),
+            password: std::env::var("DATABASE_PASSWORD")
+                .unwrap_or_else(|_| "postgres".to_string()),
+            database_name: std::env::var("DATABASE_NAME")
+                .unwrap_or_else(|_| "postgres".to_string()),
+            max_connections: env_parse("DATABASE_MAX_CONNECTIONS").unwrap_or(10),
+            acquire_timeout: Duration::from_secs(
+                env_parse("DATABASE_ACQUIRE_TIMEOUT_SECS").unwrap_or(3),
+            ),
+        }
+    }
+
+    /// Builds connect options from the individual parts. Going through
+    /// `PgConnectOptions` setters, rather than interpolating a
+    /// `postgres://` string, means a user or password containing `@`, `:`,
+    /// `/` or `%` can't corrupt the URL.
+    pub fn connect_options(&self) -> PgConnectOptions {
+        PgConnectOptions::new()
+            .host(&self.host)
+            .port(self.port)
+            .username(&self.user)
+            .password(&self.password)
+            .database(&self.database_name)
+    }
+
+    /// Builds a pool sized and timed out according to these settings.
+    pub async fn build_pool(&self) -> Result<PgPool, sqlx::Error> {
+        PgPoolOptions::new()
+            .max_connections(self.max_connections)
+            .acquire_timeout(self.acquire_timeout)
+            .connect_with(self.connect_options())
+            .await
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|value| value.parse().ok())
+}
@@ -0,0 +1,98 @@
+//! Ephemeral per-test Postgres databases, so tests can run concurrently
+//! without sharing or leaking state.
+
+use sqlx::postgres::PgPoolOptions;
+use sqlx::{Connection, Executor, PgConnection, PgPool};
+use uuid::Uuid;
+
+/// A disposable Postgres database created fresh for a single test and
+/// dropped once the guard goes out of scope.
+pub struct TestDb {
+    name: String,
+    maintenance_url: String,
+    pool: Option<PgPool>,
+}
+
+impl TestDb {
+    /// Connects to the maintenance database at `maintenance_url`, creates a
+    /// uuid-suffixed database, runs migrations against it, and returns a
+    /// guard holding a pool bound to the fresh database.
+    pub async fn new(maintenance_url: &str) -> anyhow::Result<Self> {
+        let name = format!("test_{}", Uuid::new_v4().simple());
+
+        let mut conn = PgConnection::connect(maintenance_url).await?;
+        conn.execute(format!(r#"CREATE DATABASE "{name}""#).as_str())
+            .await?;
+
+        let db_url = format!("{maintenance_url}/{name}");
+        let pool = PgPoolOptions::new().connect(&db_url).await?;
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(Self {
+            name,
+            maintenance_url: maintenance_url.to_string(),
+            pool: Some(pool),
+        })
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        self.pool.as_ref().expect("pool already torn down")
+    }
+}
+
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        let maintenance_url = self.maintenance_url.clone();
+        let name = self.name.clone();
+        let pool = self.pool.take();
+
+        let (done_tx, done_rx) = std::sync::mpsc::channel::<()>();
+
+        // `Drop` can't be async and the caller's `#[tokio::test]` runtime is
+        // already busy dropping us, so teardown runs on its own thread and
+        // runtime instead of trying to block on the current one.
+        std::thread::spawn(move || {
+            let rt = match tokio::runtime::Runtime::new() {
+                Ok(rt) => rt,
+                Err(_) => {
+                    let _ = done_tx.send(());
+                    return;
+                }
+            };
+
+            rt.block_on(async move {
+                if let Some(pool) = pool {
+                    // Close our own connections first: `DROP DATABASE` hangs
+                    // on any connection still open against the target db.
+                    pool.close().await;
+                }
+
+                let Ok(mut conn) = PgConnection::connect(&maintenance_url).await else {
+                    return;
+                };
+
+                let _ = conn
+                    .execute(
+                        format!(
+                            r#"SELECT pg_terminate_backend(pid)
+                               FROM pg_stat_activity
+                               WHERE datname = '{name}' AND pid <> pg_backend_pid()"#
+                        )
+                        .as_str(),
+                    )
+                    .await;
+
+                let _ = conn
+                    .execute(format!(r#"DROP DATABASE IF EXISTS "{name}""#).as_str())
+                    .await;
+            });
+
+            let _ = done_tx.send(());
+        });
+
+        // Block the calling thread until teardown actually completes, so
+        // the test binary can't exit (or a following test start) before the
+        // database is dropped.
+        let _ = done_rx.recv();
+    }
+}
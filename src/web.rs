@@ -0,0 +1,350 @@
+//! HTTP CRUD service exposing the `ResourceRepository` over JSON.
+
+use crate::error::ResourceError;
+use crate::repository::ResourceRepository;
+use crate::resource::Resource;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use warp::http::StatusCode;
+use warp::{Filter, Rejection, Reply};
+
+type Repo = Arc<dyn ResourceRepository + Send + Sync>;
+
+const DEFAULT_LIMIT: i64 = 20;
+const MIN_LIMIT: i64 = 1;
+const MAX_LIMIT: i64 = 100;
+
+#[derive(Debug, Deserialize)]
+struct CreateResourceRequest {
+    reference: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateResourceResponse {
+    id: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateResourceRequest {
+    reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListResourcesQuery {
+    filter: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ListResourcesResponse {
+    resources: Vec<Resource>,
+    total: i64,
+}
+
+/// Builds the `/resources` CRUD routes over the given repository.
+pub fn routes(repo: Repo) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let repo = warp::any().map(move || repo.clone());
+
+    let create = warp::path("resources")
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(repo.clone())
+        .and_then(create_resource);
+
+    let list = warp::path("resources")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query())
+        .and(repo.clone())
+        .and_then(list_resources);
+
+    let read = warp::path!("resources" / i32)
+        .and(warp::get())
+        .and(repo.clone())
+        .and_then(read_resource);
+
+    let update = warp::path!("resources" / i32)
+        .and(warp::put())
+        .and(warp::body::json())
+        .and(repo.clone())
+        .and_then(update_resource);
+
+    let delete = warp::path!("resources" / i32)
+        .and(warp::delete())
+        .and(repo)
+        .and_then(delete_resource);
+
+    create.or(list).or(read).or(update).or(delete)
+}
+
+async fn create_resource(
+    body: CreateResourceRequest,
+    repo: Repo,
+) -> Result<impl Reply, Rejection> {
+    match repo.create_resource(body.reference).await {
+        Ok(id) => Ok(warp::reply::with_status(
+            warp::reply::json(&CreateResourceResponse { id }),
+            StatusCode::CREATED,
+        )),
+        Err(err) => Ok(error_reply(&err)),
+    }
+}
+
+async fn list_resources(
+    query: ListResourcesQuery,
+    repo: Repo,
+) -> Result<impl Reply, Rejection> {
+    let offset = query.offset.unwrap_or(0);
+    if offset < 0 {
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({ "error": "offset must not be negative" })),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .clamp(MIN_LIMIT, MAX_LIMIT);
+
+    let resources = match repo
+        .list_resources(query.filter.clone(), limit, offset)
+        .await
+    {
+        Ok(resources) => resources,
+        Err(err) => return Ok(error_reply(&err)),
+    };
+
+    let total = match repo.count_resources(query.filter).await {
+        Ok(total) => total,
+        Err(err) => return Ok(error_reply(&err)),
+    };
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&ListResourcesResponse { resources, total }),
+        StatusCode::OK,
+    ))
+}
+
+async fn read_resource(id: i32, repo: Repo) -> Result<impl Reply, Rejection> {
+    match repo.read_resource(id).await {
+        Ok(resource) => Ok(warp::reply::with_status(
+            warp::reply::json(&resource),
+            StatusCode::OK,
+        )),
+        Err(err) => Ok(error_reply(&err)),
+    }
+}
+
+async fn update_resource(
+    id: i32,
+    body: UpdateResourceRequest,
+    repo: Repo,
+) -> Result<impl Reply, Rejection> {
+    match repo.update_resource(id, body.reference).await {
+        Ok(true) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({})),
+            StatusCode::OK,
+        )),
+        Ok(false) => Ok(error_reply(&ResourceError::NotFound)),
+        Err(err) => Ok(error_reply(&err)),
+    }
+}
+
+async fn delete_resource(id: i32, repo: Repo) -> Result<impl Reply, Rejection> {
+    let reply = match repo.delete_resource(id).await {
+        Ok(true) => {
+            warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT).into_response()
+        }
+        Ok(false) => error_reply(&ResourceError::NotFound).into_response(),
+        Err(err) => error_reply(&err).into_response(),
+    };
+    Ok(reply)
+}
+
+fn error_reply(err: &ResourceError) -> warp::reply::WithStatus<warp::reply::Json> {
+    let status = match err {
+        ResourceError::NotFound => StatusCode::NOT_FOUND,
+        ResourceError::Duplicate => StatusCode::CONFLICT,
+        ResourceError::Database(_) | ResourceError::Pool(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    warp::reply::with_status(
+        warp::reply::json(&serde_json::json!({ "error": err.to_string() })),
+        status,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// An in-memory stand-in for `ResourceRepository`, so the HTTP layer's
+    /// status-code and body behavior can be tested without a database.
+    #[derive(Default)]
+    struct MockRepo {
+        resources: Mutex<Vec<Resource>>,
+    }
+
+    #[async_trait]
+    impl ResourceRepository for MockRepo {
+        async fn create_resource(&self, reference: String) -> Result<i32, ResourceError> {
+            let mut resources = self.resources.lock().unwrap();
+            let id = resources.len() as i32 + 1;
+            resources.push(Resource {
+                id: Some(id),
+                reference: Some(reference),
+            });
+            Ok(id)
+        }
+
+        async fn read_resource(&self, id: i32) -> Result<Resource, ResourceError> {
+            self.resources
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|resource| resource.id == Some(id))
+                .cloned()
+                .ok_or(ResourceError::NotFound)
+        }
+
+        async fn update_resource(
+            &self,
+            id: i32,
+            reference: String,
+        ) -> Result<bool, ResourceError> {
+            let mut resources = self.resources.lock().unwrap();
+            match resources.iter_mut().find(|resource| resource.id == Some(id)) {
+                Some(resource) => {
+                    resource.reference = Some(reference);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn delete_resource(&self, id: i32) -> Result<bool, ResourceError> {
+            let mut resources = self.resources.lock().unwrap();
+            let len_before = resources.len();
+            resources.retain(|resource| resource.id != Some(id));
+            Ok(resources.len() != len_before)
+        }
+
+        async fn list_resources(
+            &self,
+            filter: Option<String>,
+            limit: i64,
+            offset: i64,
+        ) -> Result<Vec<Resource>, ResourceError> {
+            let resources = self.resources.lock().unwrap();
+            let matching = resources.iter().filter(|resource| match &filter {
+                Some(pattern) => resource
+                    .reference
+                    .as_deref()
+                    .is_some_and(|reference| reference.contains(pattern.as_str())),
+                None => true,
+            });
+
+            Ok(matching
+                .skip(offset as usize)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn count_resources(&self, _filter: Option<String>) -> Result<i64, ResourceError> {
+            Ok(self.resources.lock().unwrap().len() as i64)
+        }
+    }
+
+    fn mock_repo() -> Repo {
+        Arc::new(MockRepo::default())
+    }
+
+    #[tokio::test]
+    async fn test_create_resource_returns_201_with_id() {
+        let filter = routes(mock_repo());
+
+        let resp = warp::test::request()
+            .method("POST")
+            .path("/resources")
+            .json(&serde_json::json!({ "reference": "TEST" }))
+            .reply(&filter)
+            .await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body: CreateResourceResponse = serde_json::from_slice(resp.body()).unwrap();
+        assert_eq!(body.id, 1);
+    }
+
+    #[tokio::test]
+    async fn test_read_missing_resource_returns_404() {
+        let filter = routes(mock_repo());
+
+        let resp = warp::test::request()
+            .method("GET")
+            .path("/resources/42")
+            .reply(&filter)
+            .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_delete_resource_returns_204_with_empty_body() {
+        let filter = routes(mock_repo());
+
+        let created = warp::test::request()
+            .method("POST")
+            .path("/resources")
+            .json(&serde_json::json!({ "reference": "TEST" }))
+            .reply(&filter)
+            .await;
+        let created: CreateResourceResponse = serde_json::from_slice(created.body()).unwrap();
+
+        let resp = warp::test::request()
+            .method("DELETE")
+            .path(&format!("/resources/{}", created.id))
+            .reply(&filter)
+            .await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert!(resp.body().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_resources_rejects_negative_offset() {
+        let filter = routes(mock_repo());
+
+        let resp = warp::test::request()
+            .method("GET")
+            .path("/resources?offset=-1")
+            .reply(&filter)
+            .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_list_resources_clamps_limit_to_max() {
+        let repo = mock_repo();
+        for i in 0..150 {
+            repo.create_resource(format!("item-{i}")).await.unwrap();
+        }
+        let filter = routes(repo);
+
+        let resp = warp::test::request()
+            .method("GET")
+            .path("/resources?limit=1000")
+            .reply(&filter)
+            .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: ListResourcesResponse = serde_json::from_slice(resp.body()).unwrap();
+        assert_eq!(body.resources.len(), MAX_LIMIT as usize);
+    }
+}